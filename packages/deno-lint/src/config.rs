@@ -0,0 +1,199 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use deno_lint::rules::LintRule;
+use deno_lint::rules::{get_all_rules, get_recommended_rules};
+use napi::bindgen_prelude::*;
+use napi_derive::napi;
+use serde::Deserialize;
+
+/// `files` section of the JSON config, mirroring ESLint-style include/exclude globs.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FilesConfig {
+  #[serde(default)]
+  pub include: Vec<String>,
+  #[serde(default)]
+  pub exclude: Vec<String>,
+}
+
+/// How a rule's diagnostics count toward the overall lint result: `off`
+/// drops the rule entirely, `warn` is reported but never fails the run on
+/// its own, `error` fails it same as today.
+#[napi]
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+  Off,
+  Warn,
+  #[default]
+  Error,
+}
+
+/// `rules` section of the JSON config.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RulesConfig {
+  #[serde(default)]
+  pub all: bool,
+  #[serde(default)]
+  pub include: Vec<String>,
+  #[serde(default)]
+  pub exclude: Vec<String>,
+  /// Per-rule severity override, e.g. `{"no-explicit-any": "warn"}`. Rules
+  /// not listed here keep their default severity (`error`).
+  #[serde(default)]
+  pub severity: HashMap<String, Severity>,
+}
+
+/// Default cap on a single file's size before it's skipped instead of read
+/// and parsed; keeps one huge generated or vendored file from dominating a
+/// run. Overridable via `maxFileSize` in the config or a per-call argument.
+pub const DEFAULT_MAX_FILE_SIZE: u64 = 1024 * 1024;
+
+/// Root shape of the `denolint` JSON config file.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Config {
+  #[serde(default)]
+  pub rules: RulesConfig,
+  #[serde(default)]
+  pub files: FilesConfig,
+  /// Accumulated warnings past this count also trip the error flag. `None`
+  /// means warnings never fail the run on their own.
+  #[serde(default)]
+  pub max_warnings: Option<u32>,
+  /// Byte size above which a file is skipped rather than read and parsed.
+  /// `None` falls back to `DEFAULT_MAX_FILE_SIZE`.
+  #[serde(default)]
+  pub max_file_size: Option<u64>,
+}
+
+impl Config {
+  /// Resolves the active rule set from `all`/`include`/`exclude`, then drops
+  /// any rule with an explicit `severity: "off"` entry. This runs *after*
+  /// include/exclude resolution so `off` always wins, even for a rule named
+  /// in `include` — folding it into `exclude_rules` instead would let
+  /// `include` silently re-add it.
+  pub fn get_rules(&self) -> Vec<&'static dyn LintRule> {
+    filter_rules(
+      self.rules.all,
+      Some(self.rules.exclude.clone()),
+      Some(self.rules.include.clone()),
+    )
+    .into_iter()
+    .filter(|rule| self.severity_of(rule.code()) != Severity::Off)
+    .collect()
+  }
+
+  /// Severity to report a diagnostic from `rule_code` at; rules without an
+  /// explicit entry default to `error`, matching today's behavior.
+  pub fn severity_of(&self, rule_code: &str) -> Severity {
+    self.rules.severity.get(rule_code).copied().unwrap_or_default()
+  }
+
+  pub fn max_file_size(&self) -> u64 {
+    self.max_file_size.unwrap_or(DEFAULT_MAX_FILE_SIZE)
+  }
+}
+
+/// Picks the active rule set: every rule minus the exclude list, the
+/// recommended subset, or the recommended subset adjusted by explicit
+/// include/exclude lists.
+pub fn filter_rules(
+  all_rules: bool,
+  exclude_rules: Option<Vec<String>>,
+  include_rules: Option<Vec<String>>,
+) -> Vec<&'static dyn LintRule> {
+  let exclude_rules = exclude_rules.unwrap_or_default();
+  let include_rules = include_rules.unwrap_or_default();
+
+  if all_rules {
+    return get_all_rules()
+      .into_iter()
+      .filter(|r| !exclude_rules.contains(&r.code().to_string()))
+      .collect();
+  }
+
+  let mut rules: Vec<&'static dyn LintRule> = get_recommended_rules()
+    .into_iter()
+    .filter(|r| !exclude_rules.contains(&r.code().to_string()))
+    .collect();
+
+  if !include_rules.is_empty() {
+    for rule in get_all_rules() {
+      if include_rules.contains(&rule.code().to_string())
+        && !rules.iter().any(|r| r.code() == rule.code())
+      {
+        rules.push(rule);
+      }
+    }
+  }
+
+  rules
+}
+
+pub fn load_from_json(path: &Path) -> Result<Config> {
+  let content = fs::read_to_string(path)
+    .map_err(|e| Error::from_reason(format!("Read config {:?} failed: {}", path, e)))?;
+  serde_json::from_str(&content)
+    .map_err(|e| Error::from_reason(format!("Parse config {:?} failed: {}", path, e)))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn recommended_rules_are_non_empty_by_default() {
+    let rules = filter_rules(false, None, None);
+    assert!(!rules.is_empty());
+  }
+
+  #[test]
+  fn exclude_drops_a_recommended_rule() {
+    let code = get_recommended_rules()[0].code();
+    let rules = filter_rules(false, Some(vec![code.to_string()]), None);
+    assert!(!rules.iter().any(|r| r.code() == code));
+  }
+
+  #[test]
+  fn include_adds_a_non_recommended_rule() {
+    let recommended: Vec<&str> = get_recommended_rules().iter().map(|r| r.code()).collect();
+    let extra = get_all_rules()
+      .into_iter()
+      .find(|r| !recommended.contains(&r.code()))
+      .expect("at least one non-recommended rule should exist");
+    let rules = filter_rules(false, None, Some(vec![extra.code().to_string()]));
+    assert!(rules.iter().any(|r| r.code() == extra.code()));
+  }
+
+  #[test]
+  fn severity_off_wins_even_when_rule_is_also_included() {
+    let recommended: Vec<&str> = get_recommended_rules().iter().map(|r| r.code()).collect();
+    let extra = get_all_rules()
+      .into_iter()
+      .find(|r| !recommended.contains(&r.code()))
+      .expect("at least one non-recommended rule should exist");
+
+    let mut cfg = Config {
+      rules: RulesConfig { include: vec![extra.code().to_string()], ..Default::default() },
+      ..Default::default()
+    };
+    cfg.rules.severity.insert(extra.code().to_string(), Severity::Off);
+
+    let rules = cfg.get_rules();
+    assert!(!rules.iter().any(|r| r.code() == extra.code()));
+  }
+
+  #[test]
+  fn severity_off_on_a_recommended_rule_drops_it() {
+    let code = get_recommended_rules()[0].code().to_string();
+    let mut cfg = Config::default();
+    cfg.rules.severity.insert(code.clone(), Severity::Off);
+
+    let rules = cfg.get_rules();
+    assert!(!rules.iter().any(|r| r.code() == code));
+  }
+}
@@ -0,0 +1,237 @@
+use std::fmt::Write;
+
+use deno_ast::SourceTextInfo;
+use deno_lint::diagnostic::LintDiagnostic;
+use napi::bindgen_prelude::*;
+use napi_derive::napi;
+
+/// How diagnostics are handed back to the caller: human-readable text, a
+/// single line per diagnostic (location, code and message only, without the
+/// snippet or hint), or structured objects for editor/CI integrations.
+#[napi]
+#[derive(Clone, Copy, Default)]
+pub enum ReportFormat {
+  #[default]
+  Pretty,
+  Compact,
+  Json,
+}
+
+/// Zero-based and one-based position of one end of a diagnostic range.
+#[napi(object)]
+#[derive(serde::Serialize)]
+pub struct DiagnosticPosition {
+  pub line: u32,
+  pub column: u32,
+  pub line1: u32,
+  pub column1: u32,
+}
+
+#[napi(object)]
+#[derive(serde::Serialize)]
+pub struct DiagnosticRecord {
+  pub filename: String,
+  pub code: String,
+  pub message: String,
+  pub start: DiagnosticPosition,
+  pub end: DiagnosticPosition,
+  pub snippet: String,
+  pub hint: Option<String>,
+}
+
+fn position_at(text_info: &SourceTextInfo, pos: deno_ast::SourcePos) -> DiagnosticPosition {
+  let index = text_info.line_and_column_index(pos);
+  DiagnosticPosition {
+    line: index.line_index as u32,
+    column: index.column_index as u32,
+    line1: index.line_index as u32 + 1,
+    column1: index.column_index as u32 + 1,
+  }
+}
+
+fn snippet_for(text_info: &SourceTextInfo, diagnostic: &LintDiagnostic) -> String {
+  match diagnostic.range {
+    Some(range) => text_info.line_text(text_info.line_index(range.start)).to_string(),
+    None => String::new(),
+  }
+}
+
+/// Renders diagnostics as human-readable, multi-line report entries. This is
+/// the default format, meant for printing straight to a terminal.
+pub fn display_diagnostics(
+  diagnostics: &[LintDiagnostic],
+  text_info: &SourceTextInfo,
+  file_name: &str,
+) -> std::result::Result<Vec<String>, std::fmt::Error> {
+  let mut output = Vec::with_capacity(diagnostics.len());
+  for diagnostic in diagnostics {
+    let mut entry = String::new();
+    let code = diagnostic.details.code.as_str();
+    let message = diagnostic.details.message.as_str();
+    write!(entry, "({code}) {message}")?;
+    if let Some(range) = diagnostic.range {
+      let start = position_at(text_info, range.start);
+      write!(entry, "\n  at {file_name}:{}:{}", start.line1, start.column1)?;
+      let snippet = snippet_for(text_info, diagnostic);
+      if !snippet.is_empty() {
+        write!(entry, "\n  {}", snippet.trim_end())?;
+      }
+    } else {
+      write!(entry, "\n  at {file_name}")?;
+    }
+    if let Some(hint) = &diagnostic.details.hint {
+      write!(entry, "\n  hint: {hint}")?;
+    }
+    output.push(entry);
+  }
+  Ok(output)
+}
+
+/// Renders diagnostics as one line each, e.g. for log aggregation.
+pub fn compact_diagnostics(
+  diagnostics: &[LintDiagnostic],
+  text_info: &SourceTextInfo,
+  file_name: &str,
+) -> Vec<String> {
+  diagnostics
+    .iter()
+    .map(|diagnostic| {
+      let code = diagnostic.details.code.as_str();
+      let message = diagnostic.details.message.as_str();
+      match diagnostic.range {
+        Some(range) => {
+          let start = position_at(text_info, range.start);
+          format!("{file_name}:{}:{}: ({code}) {message}", start.line1, start.column1)
+        }
+        None => format!("{file_name}: ({code}) {message}"),
+      }
+    })
+    .collect()
+}
+
+/// Renders diagnostics as plain data, for editor/CI integrations that want to
+/// consume results directly instead of scraping formatted strings.
+pub fn json_diagnostics(
+  diagnostics: &[LintDiagnostic],
+  text_info: &SourceTextInfo,
+  file_name: &str,
+) -> Vec<DiagnosticRecord> {
+  diagnostics
+    .iter()
+    .map(|diagnostic| {
+      let (start, end, snippet) = match diagnostic.range {
+        Some(range) => (
+          position_at(text_info, range.start),
+          position_at(text_info, range.end),
+          snippet_for(text_info, diagnostic),
+        ),
+        None => (
+          DiagnosticPosition { line: 0, column: 0, line1: 1, column1: 1 },
+          DiagnosticPosition { line: 0, column: 0, line1: 1, column1: 1 },
+          String::new(),
+        ),
+      };
+      DiagnosticRecord {
+        filename: file_name.to_owned(),
+        code: diagnostic.details.code.to_string(),
+        message: diagnostic.details.message.to_string(),
+        start,
+        end,
+        snippet,
+        hint: diagnostic.details.hint.as_ref().map(|h| h.to_string()),
+      }
+    })
+    .collect()
+}
+
+/// Synthesizes a diagnostic-shaped report for a file that was skipped for
+/// exceeding the configured size limit, so the coverage gap is visible
+/// instead of the file silently vanishing from results.
+pub fn oversized_file_report(
+  format: ReportFormat,
+  file_name: &str,
+  size: u64,
+  limit: u64,
+) -> Either<Vec<String>, Vec<DiagnosticRecord>> {
+  let message = format!("file too large, size {size} exceeds limit {limit}");
+  let zero = DiagnosticPosition { line: 0, column: 0, line1: 1, column1: 1 };
+  match format {
+    ReportFormat::Json => Either::B(vec![DiagnosticRecord {
+      filename: file_name.to_owned(),
+      code: "file-too-large".to_owned(),
+      message,
+      start: zero,
+      end: DiagnosticPosition { line: 0, column: 0, line1: 1, column1: 1 },
+      snippet: String::new(),
+      hint: None,
+    }]),
+    ReportFormat::Compact => Either::A(vec![format!("{file_name}: (file-too-large) {message}")]),
+    ReportFormat::Pretty => Either::A(vec![format!("(file-too-large) {message}\n  at {file_name}")]),
+  }
+}
+
+/// Dispatches to the requested reporter and wraps the result so napi can hand
+/// either plain strings or structured records back to JS from one call site.
+pub fn report_diagnostics(
+  format: ReportFormat,
+  diagnostics: &[LintDiagnostic],
+  text_info: &SourceTextInfo,
+  file_name: &str,
+) -> Result<Either<Vec<String>, Vec<DiagnosticRecord>>> {
+  match format {
+    ReportFormat::Pretty => Ok(Either::A(
+      display_diagnostics(diagnostics, text_info, file_name)
+        .map_err(|err| Error::new(Status::GenericFailure, format!("{err}")))?,
+    )),
+    ReportFormat::Compact => Ok(Either::A(compact_diagnostics(diagnostics, text_info, file_name))),
+    ReportFormat::Json => Ok(Either::B(json_diagnostics(diagnostics, text_info, file_name))),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn json_format_returns_a_single_structured_record() {
+    let report = oversized_file_report(ReportFormat::Json, "big.ts", 2_000_000, 1_048_576);
+    let records = match report {
+      Either::B(records) => records,
+      Either::A(_) => panic!("Json format should return Either::B"),
+    };
+    assert_eq!(records.len(), 1);
+    let record = &records[0];
+    assert_eq!(record.filename, "big.ts");
+    assert_eq!(record.code, "file-too-large");
+    assert_eq!(record.message, "file too large, size 2000000 exceeds limit 1048576");
+    assert_eq!(record.start.line1, 1);
+    assert_eq!(record.start.column1, 1);
+    assert_eq!(record.end.line1, 1);
+    assert_eq!(record.end.column1, 1);
+    assert!(record.snippet.is_empty());
+    assert!(record.hint.is_none());
+  }
+
+  #[test]
+  fn compact_format_returns_a_single_line_string() {
+    let report = oversized_file_report(ReportFormat::Compact, "big.ts", 2_000_000, 1_048_576);
+    let lines = match report {
+      Either::A(lines) => lines,
+      Either::B(_) => panic!("Compact format should return Either::A"),
+    };
+    assert_eq!(lines, vec!["big.ts: (file-too-large) file too large, size 2000000 exceeds limit 1048576"]);
+  }
+
+  #[test]
+  fn pretty_format_returns_a_multi_line_entry() {
+    let report = oversized_file_report(ReportFormat::Pretty, "big.ts", 2_000_000, 1_048_576);
+    let lines = match report {
+      Either::A(lines) => lines,
+      Either::B(_) => panic!("Pretty format should return Either::A"),
+    };
+    assert_eq!(
+      lines,
+      vec!["(file-too-large) file too large, size 2000000 exceeds limit 1048576\n  at big.ts"]
+    );
+  }
+}
@@ -0,0 +1,158 @@
+use std::borrow::Cow;
+
+use deno_ast::{ParsedSource, SourceTextInfo};
+use deno_lint::diagnostic::LintDiagnostic;
+use napi::bindgen_prelude::*;
+use napi_derive::napi;
+
+/// Rules whose fixes are only *suggested*: applying them can change behavior
+/// rather than being a guaranteed no-op rewrite, so they are skipped unless
+/// the caller opts into unsafe fixes.
+const SUGGESTED_ONLY_RULES: &[&str] = &["no-unused-vars", "no-explicit-any"];
+
+fn is_safe(rule_code: &str) -> bool {
+  !SUGGESTED_ONLY_RULES.contains(&rule_code)
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Edit {
+  start: usize,
+  end: usize,
+  new_text: Cow<'static, str>,
+}
+
+fn byte_offset(text_info: &SourceTextInfo, pos: deno_ast::SourcePos) -> usize {
+  pos.as_byte_index(text_info.range().start)
+}
+
+/// Applies every eligible fix from one lint pass to `source`. Fixes are
+/// sorted by range start and spliced from the end of the buffer toward the
+/// beginning so earlier offsets stay valid; a fix whose range overlaps one
+/// already accepted in this pass is dropped and left for a later pass.
+/// Returns the patched text and whether anything changed.
+fn apply_one_pass(
+  source: &str,
+  diagnostics: &[LintDiagnostic],
+  text_info: &SourceTextInfo,
+  include_unsafe: bool,
+) -> (String, bool) {
+  let mut edits: Vec<Edit> = diagnostics
+    .iter()
+    .filter(|d| include_unsafe || is_safe(&d.details.code))
+    // Each diagnostic may offer several alternative fixes (e.g. "add import"
+    // vs. "rename identifier"); only the first is the preferred one, so take
+    // at most one fix per diagnostic rather than applying every alternative.
+    .filter_map(|d| d.details.fixes.first())
+    .flat_map(|f| f.changes.iter())
+    .map(|change| Edit {
+      start: byte_offset(text_info, change.range.start),
+      end: byte_offset(text_info, change.range.end),
+      new_text: change.new_text.clone(),
+    })
+    .collect();
+
+  splice_edits(source, edits)
+}
+
+/// Drops edits whose range overlaps one already accepted (earliest start
+/// wins, ties broken by encounter order) and splices the rest into `source`
+/// from the end of the buffer toward the beginning so earlier offsets stay
+/// valid. Returns the patched text and whether anything changed. Pure and
+/// deno_lint-free so it can be exercised directly in tests.
+fn splice_edits(source: &str, mut edits: Vec<Edit>) -> (String, bool) {
+  if edits.is_empty() {
+    return (source.to_owned(), false);
+  }
+  edits.sort_by_key(|e| e.start);
+
+  let mut accepted = Vec::with_capacity(edits.len());
+  let mut cursor = 0usize;
+  for edit in edits {
+    if edit.start < cursor {
+      continue;
+    }
+    cursor = edit.end;
+    accepted.push(edit);
+  }
+  if accepted.is_empty() {
+    return (source.to_owned(), false);
+  }
+
+  let mut patched = source.to_owned();
+  for edit in accepted.iter().rev() {
+    patched.replace_range(edit.start..edit.end, edit.new_text.as_ref());
+  }
+  (patched, true)
+}
+
+/// A small fixed bound on fix/re-lint passes: applying one fix can expose a
+/// diagnostic that is itself fixable, but this must not loop forever.
+const MAX_FIX_PASSES: usize = 5;
+
+/// Repeatedly applies fixes and re-lints via `relint` until a pass makes no
+/// further change or `MAX_FIX_PASSES` is reached, since applying one fix can
+/// expose another diagnostic that is itself fixable. Returns the patched
+/// source; callers that also want the remaining diagnostics should re-lint
+/// the returned source once more, the same way they lint any other source.
+pub fn fix_source(
+  mut source: String,
+  include_unsafe: bool,
+  mut relint: impl FnMut(&str) -> Result<(ParsedSource, Vec<LintDiagnostic>)>,
+) -> Result<String> {
+  for _ in 0..MAX_FIX_PASSES {
+    let (parsed, file_diagnostics) = relint(&source)?;
+    let (patched, changed) = apply_one_pass(&source, &file_diagnostics, parsed.text_info(), include_unsafe);
+    if !changed {
+      break;
+    }
+    source = patched;
+  }
+  Ok(source)
+}
+
+/// One file's patched source, returned instead of being written to disk when
+/// `fix_dry_run` is requested.
+#[napi(object)]
+pub struct FixedFile {
+  pub filename: String,
+  pub code: String,
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn edit(start: usize, end: usize, new_text: &'static str) -> Edit {
+    Edit { start, end, new_text: Cow::Borrowed(new_text) }
+  }
+
+  #[test]
+  fn splices_non_overlapping_edits() {
+    let (patched, changed) = splice_edits("let x = 1;", vec![edit(4, 5, "y"), edit(8, 9, "2")]);
+    assert!(changed);
+    assert_eq!(patched, "let y = 2;");
+  }
+
+  #[test]
+  fn no_edits_leaves_source_unchanged() {
+    let (patched, changed) = splice_edits("let x = 1;", vec![]);
+    assert!(!changed);
+    assert_eq!(patched, "let x = 1;");
+  }
+
+  #[test]
+  fn drops_edit_overlapping_an_earlier_one() {
+    // second edit starts before the first one's range ends, so it's dropped
+    // and left for a later pass instead of corrupting the splice.
+    let (patched, changed) = splice_edits("abcdef", vec![edit(0, 3, "XYZ"), edit(2, 4, "??")]);
+    assert!(changed);
+    assert_eq!(patched, "XYZdef");
+  }
+
+  #[test]
+  fn accepts_edit_that_starts_exactly_where_prior_one_ends() {
+    let (patched, changed) = splice_edits("abcdef", vec![edit(0, 2, "AB"), edit(2, 4, "CD")]);
+    assert!(changed);
+    assert_eq!(patched, "ABCDef");
+  }
+}
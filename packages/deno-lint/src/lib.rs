@@ -13,16 +13,20 @@ use std::str;
 
 use deno_ast::MediaType;
 use deno_lint::linter::LinterBuilder;
-use deno_lint::rules::{get_all_rules, get_recommended_rules};
+use deno_lint::rules::LintRule;
 use ignore::overrides::OverrideBuilder;
-use deno_lint::rules::get_recommended_rules;
 use ignore::types::TypesBuilder;
 use ignore::WalkBuilder;
 use napi::bindgen_prelude::*;
 use napi_derive::*;
+use rayon::prelude::*;
 
 mod config;
 mod diagnostics;
+mod fix;
+
+use diagnostics::ReportFormat;
+use fix::FixedFile;
 
 #[inline(always)]
 fn get_media_type(p: &Path) -> MediaType {
@@ -65,7 +69,8 @@ fn lint(
   all_rules: Option<bool>,
   exclude_rules: Option<Vec<String>>,
   include_rules: Option<Vec<String>>,
-) -> Result<Vec<String>> {
+  format: Option<ReportFormat>,
+) -> Result<Either<Vec<String>, Vec<diagnostics::DiagnosticRecord>>> {
   let linter = LinterBuilder::default()
     .rules(config::filter_rules(
       all_rules.unwrap_or(false),
@@ -96,17 +101,110 @@ fn lint(
       )
     })?;
 
-  diagnostics::display_diagnostics(&file_diagnostics, s.text_info(), &file_name)
-    .map_err(|err| Error::new(Status::GenericFailure, format!("{err}")))
+  diagnostics::report_diagnostics(format.unwrap_or_default(), &file_diagnostics, s.text_info(), &file_name)
+}
+
+/// Stdin entry point: lints `source_code` under a virtual `file_name` that
+/// has no real path on disk, e.g. `_stdin.ts` for an unsaved editor buffer.
+/// `file_name` is used solely to pick the `MediaType`; since there's no path
+/// to resolve `.denolintignore`/`.eslintignore` against, ignore-file
+/// resolution is skipped and only inline `eslint-disable` directives apply.
+#[napi]
+fn lint_stdin(
+  file_name: String,
+  source_code: Either<String, Buffer>,
+  all_rules: Option<bool>,
+  exclude_rules: Option<Vec<String>>,
+  include_rules: Option<Vec<String>>,
+  format: Option<ReportFormat>,
+) -> Result<Either<Vec<String>, Vec<diagnostics::DiagnosticRecord>>> {
+  lint(file_name, source_code, all_rules, exclude_rules, include_rules, format)
+}
+
+#[napi(object)]
+struct LintFixResult {
+  pub code: String,
+  pub diagnostics: Vec<String>,
+}
+
+/// Fix-returning variant of `lint`: applies safe (and, with `unsafe_fixes`,
+/// suggested) autofixes to `source_code` in memory and reports whatever
+/// diagnostics remain. Never touches disk; callers decide what to do with
+/// the patched text.
+#[napi]
+fn lint_fix(
+  file_name: String,
+  source_code: Either<String, Buffer>,
+  all_rules: Option<bool>,
+  exclude_rules: Option<Vec<String>>,
+  include_rules: Option<Vec<String>>,
+  unsafe_fixes: Option<bool>,
+) -> Result<LintFixResult> {
+  let rules = config::filter_rules(all_rules.unwrap_or(false), exclude_rules, include_rules);
+  let media_type = get_media_type(Path::new(file_name.as_str()));
+
+  let source_string = match &source_code {
+    Either::A(s) => s.to_owned(),
+    Either::B(b) => str::from_utf8(b.as_ref())
+      .map_err(|e| {
+        Error::new(
+          Status::StringExpected,
+          format!("Input source is not valid utf8 string {}", e),
+        )
+      })?
+      .to_owned(),
+  };
+
+  let run_linter = |src: &str| {
+    LinterBuilder::default()
+      .rules(rules.clone())
+      .media_type(media_type)
+      .ignore_file_directive("eslint-disable")
+      .ignore_diagnostic_directive("eslint-disable-next-line")
+      .build()
+      .lint(file_name.clone(), src.to_owned())
+      .map_err(|e| {
+        Error::new(
+          Status::GenericFailure,
+          format!("Lint failed: {}, at: {}", e, file_name),
+        )
+      })
+  };
+
+  let code = fix::fix_source(source_string, unsafe_fixes.unwrap_or(false), run_linter)?;
+  let (s, remaining) = run_linter(&code)?;
+  let diagnostics = diagnostics::display_diagnostics(&remaining, s.text_info(), &file_name)
+    .map_err(|err| Error::new(Status::GenericFailure, format!("{err}")))?;
+
+  Ok(LintFixResult { code, diagnostics })
 }
 
+/// `fix_dry_run` computes fixes and reports the patched text without writing
+/// to disk; it works on its own and does not require `apply`. `apply` is what
+/// controls whether accepted fixes are written to disk instead of previewed;
+/// when `fix_dry_run` is also set, nothing is written.
+/// In `Json` format the aggregated diagnostics are handed back as structured
+/// records rather than printed, the same way `lint()` returns them, so an
+/// in-process caller doesn't have to scrape stdout; `fix_dry_run` takes
+/// priority over `Json` reporting if both are requested.
 #[napi]
 fn denolint(
   __dirname: String,
   config_path: String,
   scan_dirs: Option<Vec<String>>,
-) -> Result<bool> {
-  let mut has_error = false;
+  format: Option<ReportFormat>,
+  apply: Option<bool>,
+  unsafe_fixes: Option<bool>,
+  fix_dry_run: Option<bool>,
+  max_warnings: Option<u32>,
+  max_file_size: Option<u64>,
+) -> Result<Either3<bool, Vec<diagnostics::DiagnosticRecord>, Vec<FixedFile>>> {
+  let format = format.unwrap_or_default();
+  let apply = apply.unwrap_or(false);
+  let unsafe_fixes = unsafe_fixes.unwrap_or(false);
+  let fix_dry_run = fix_dry_run.unwrap_or(false);
+  let mut json_records: Vec<diagnostics::DiagnosticRecord> = Vec::new();
+  let mut fixed_files: Vec<FixedFile> = Vec::new();
   let cwd = env::current_dir().map_err(|e| {
     Error::new(
       Status::GenericFailure,
@@ -117,12 +215,16 @@ fn denolint(
     .map(|m| m.is_file())
     .unwrap_or(false);
 
-  let (rules, cfg_ignore_files, cfg_add_files) = if config_existed {
-    let cfg = config::load_from_json(path::Path::new(&config_path))?;
-    (cfg.get_rules(), cfg.files.exclude, cfg.files.include)
+  let cfg = if config_existed {
+    config::load_from_json(path::Path::new(&config_path))?
   } else {
-    (get_recommended_rules(), vec![], vec![])
+    config::Config::default()
   };
+  let rules = cfg.get_rules();
+  let cfg_ignore_files = cfg.files.exclude.clone();
+  let cfg_add_files = cfg.files.include.clone();
+  let max_warnings = max_warnings.or(cfg.max_warnings);
+  let max_file_size = max_file_size.unwrap_or_else(|| cfg.max_file_size());
 
   let mut eslint_ignore_file = cwd.clone();
 
@@ -165,16 +267,19 @@ fn denolint(
       Err(_) => __dirname.as_str(),
     },
   };
-  let mut dir_walker = WalkBuilder::new(cwd.clone());
   let dir = if !cfg_add_files.is_empty() {
     make_absolute(&cfg_add_files[0], &cwd)
   } else {
     cwd.clone()
   };
-  let mut dir_walker = WalkBuilder::new(dir);
   let dirs = scan_dirs.unwrap_or_default();
+  // `scan_dirs[0]` (if given) wins outright; otherwise the walk root is the
+  // first `files.include` entry from the config, falling back to `cwd` when
+  // neither is set. This is a deliberate behavior change from scanning `cwd`
+  // unconditionally: it makes `files.include`'s first entry actually usable
+  // as a scan root instead of being silently ignored by `denolint`.
   let root = if dirs.is_empty() {
-    cwd.as_path()
+    dir.as_path()
   } else {
     Path::new(&dirs[0])
   };
@@ -184,10 +289,10 @@ fn denolint(
     .types(types)
     .follow_links(true);
   if !cfg_ignore_files.is_empty() {
-    let mut overrides = OverrideBuilder::new(cwd);
-    for f in cfg_ignore_files {
+    let mut overrides = OverrideBuilder::new(&cwd);
+    for f in &cfg_ignore_files {
       let mut r = "!".to_string();
-      r.push_str(&f);
+      r.push_str(f);
       overrides
         .add(&r)
         .unwrap_or_else(|_| panic!("Adding excluded file {:?} failed", f));
@@ -196,6 +301,7 @@ fn denolint(
       .build()
       .unwrap_or_else(|_| panic!("Applying files.exclude from {:?} failed", config_path));
     dir_walker.overrides(o);
+  }
   for i in cfg_add_files.iter().skip(1) {
     dir_walker.add(&make_absolute(i, &cwd));
   }
@@ -205,40 +311,144 @@ fn denolint(
   for i in cfg_ignore_files {
     dir_walker.add_ignore(i);
   }
-  for entry in dir_walker.build().filter_map(|v| v.ok()) {
-    let p = entry.path();
-    if p.is_file() {
-      let file_content = fs::read_to_string(&p)
-        .map_err(|e| Error::from_reason(format!("Read file {:?} failed: {}", p, e)))?;
-
-      let linter = LinterBuilder::default()
-        .rules(rules.clone())
-        .media_type(get_media_type(p))
+
+  // Walking stays single-threaded (the ignore crate's own parallel walker
+  // doesn't preserve order), but it's cheap; the expensive read+parse+lint
+  // work below is what gets spread across a worker pool.
+  let paths: Vec<PathBuf> = dir_walker
+    .build()
+    .filter_map(|v| v.ok())
+    .map(|entry| entry.into_path())
+    .filter(|p| p.is_file())
+    .collect();
+
+  let mut results: Vec<FileResult> = paths
+    .par_iter()
+    .map(|p| lint_file(p, &rules, &cfg, format, apply, unsafe_fixes, fix_dry_run, max_file_size))
+    .collect::<Result<_>>()?;
+  results.sort_by(|a, b| a.path.cmp(&b.path));
+
+  let mut warning_count = 0u32;
+  let mut has_error = false;
+  for result in results {
+    has_error = has_error || result.error_count > 0;
+    warning_count += result.warning_count;
+    match result.report {
+      Either::A(issues) => {
+        for issue in issues {
+          eprintln!("{issue}")
+        }
+      }
+      Either::B(records) => json_records.extend(records),
+    }
+    if let Some(fixed) = result.fixed_file {
+      fixed_files.push(fixed);
+    }
+  }
+
+  if let Some(limit) = max_warnings {
+    has_error = has_error || warning_count > limit;
+  }
+
+  // `fix_dry_run` is the more specific ask, so its preview wins if both it
+  // and JSON reporting are requested together.
+  if fix_dry_run {
+    return Ok(Either3::C(fixed_files));
+  }
+
+  if matches!(format, ReportFormat::Json) {
+    return Ok(Either3::B(json_records));
+  }
+
+  Ok(Either3::A(has_error))
+}
+
+struct FileResult {
+  path: PathBuf,
+  error_count: u32,
+  warning_count: u32,
+  report: Either<Vec<String>, Vec<diagnostics::DiagnosticRecord>>,
+  fixed_file: Option<FixedFile>,
+}
+
+/// Reads, optionally fixes, and lints one file. Takes only borrowed/owned
+/// inputs so it can run independently on any worker thread.
+fn lint_file(
+  p: &Path,
+  rules: &[&'static dyn LintRule],
+  cfg: &config::Config,
+  format: ReportFormat,
+  apply: bool,
+  unsafe_fixes: bool,
+  fix_dry_run: bool,
+  max_file_size: u64,
+) -> Result<FileResult> {
+  let path_str = p
+    .to_str()
+    .ok_or_else(|| Error::from_reason(format!("Convert path to string failed: {:?}", p)))?
+    .to_owned();
+
+  let size = fs::metadata(p)
+    .map_err(|e| Error::from_reason(format!("Stat file {:?} failed: {}", p, e)))?
+    .len();
+  if size > max_file_size {
+    let report = diagnostics::oversized_file_report(format, &path_str, size, max_file_size);
+    return Ok(FileResult { path: p.to_path_buf(), error_count: 0, warning_count: 0, report, fixed_file: None });
+  }
+
+  let file_content =
+    fs::read_to_string(p).map_err(|e| Error::from_reason(format!("Read file {:?} failed: {}", p, e)))?;
+  let media_type = get_media_type(p);
+
+  let mut content = file_content.clone();
+  let mut fixed_file = None;
+  if apply || fix_dry_run {
+    let path_for_fix = path_str.clone();
+    content = fix::fix_source(content, unsafe_fixes, |src| {
+      LinterBuilder::default()
+        .rules(rules.to_vec())
+        .media_type(media_type)
         .ignore_file_directive("eslint-disable")
         .ignore_diagnostic_directive("eslint-disable-next-line")
-        .build();
-      let (s, file_diagnostics) = linter
-        .lint(
-          p.to_str()
-            .ok_or_else(|| Error::from_reason(format!("Convert path to string failed: {:?}", &p)))?
-            .to_owned(),
-          file_content.clone(),
-        )
+        .build()
+        .lint(path_for_fix.clone(), src.to_owned())
         .map_err(|e| {
           Error::new(
             Status::GenericFailure,
-            format!("Lint failed: {}, at: {:?}", e, &p),
+            format!("Lint failed: {}, at: {}", e, path_for_fix),
           )
-        })?;
-      has_error = has_error || !file_diagnostics.is_empty();
-      for issue in
-        diagnostics::display_diagnostics(&file_diagnostics, s.text_info(), p.to_str().unwrap())
-          .map_err(|err| Error::new(Status::GenericFailure, format!("{err}")))?
-      {
-        eprintln!("{issue}")
+        })
+    })?;
+    if content != file_content {
+      if fix_dry_run {
+        fixed_file = Some(FixedFile { filename: path_str.clone(), code: content.clone() });
+      } else {
+        fs::write(p, &content)
+          .map_err(|e| Error::from_reason(format!("Write file {:?} failed: {}", p, e)))?;
       }
     }
   }
 
-  Ok(has_error)
+  let linter = LinterBuilder::default()
+    .rules(rules.to_vec())
+    .media_type(media_type)
+    .ignore_file_directive("eslint-disable")
+    .ignore_diagnostic_directive("eslint-disable-next-line")
+    .build();
+  let (s, file_diagnostics) = linter
+    .lint(path_str.clone(), content)
+    .map_err(|e| Error::new(Status::GenericFailure, format!("Lint failed: {}, at: {:?}", e, p)))?;
+
+  let mut error_count = 0u32;
+  let mut warning_count = 0u32;
+  for diagnostic in &file_diagnostics {
+    match cfg.severity_of(&diagnostic.details.code) {
+      config::Severity::Error => error_count += 1,
+      config::Severity::Warn => warning_count += 1,
+      config::Severity::Off => {}
+    }
+  }
+  let report = diagnostics::report_diagnostics(format, &file_diagnostics, s.text_info(), &path_str)?;
+
+  Ok(FileResult { path: p.to_path_buf(), error_count, warning_count, report, fixed_file })
 }